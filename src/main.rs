@@ -1,15 +1,64 @@
 use zellij_tile::prelude::*;
 
-use std::collections::{HashMap, BTreeMap};
+use regex::Regex;
+
+use std::collections::{HashMap, BTreeMap, HashSet, VecDeque};
+
+const RECENT_FILTERS_CAPACITY: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    Fuzzy,
+    Exact,
+    Regex,
+}
+
+impl Default for SearchMode {
+    fn default() -> Self {
+        SearchMode::Fuzzy
+    }
+}
+
+impl SearchMode {
+    pub fn from_config(value: &str) -> Option<Self> {
+        match value {
+            "fuzzy" => Some(SearchMode::Fuzzy),
+            "exact" => Some(SearchMode::Exact),
+            "regex" => Some(SearchMode::Regex),
+            _ => None,
+        }
+    }
+    pub fn indicator(&self) -> &'static str {
+        match self {
+            SearchMode::Fuzzy => "[fuzzy]",
+            SearchMode::Exact => "[exact]",
+            SearchMode::Regex => "[regex]",
+        }
+    }
+}
 
 #[derive(Debug, Default)]
 struct State {
     filter: String,
     tabs: Vec<String>,
-    panes: HashMap<PaneIdHashable, String>, // String -> pane title
+    panes: HashMap<PaneIdHashable, PaneEntry>,
     current_matches: Vec<Match>,
     selected_tab_index: Option<usize>,
     selected_match_index: Option<usize>,
+    default_search_mode: SearchMode,
+    search_mode: SearchMode,
+    search_error: bool,
+    tab_filter: Option<usize>,
+    matching_tab_indices: Vec<usize>,
+    marked_for_extraction: HashSet<PaneIdHashable>,
+    recent_filters: VecDeque<String>, // most recent first
+    recent_filter_cursor: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaneEntry {
+    pub title: String,
+    pub tab_index: usize,
 }
 
 #[derive(Debug)]
@@ -17,14 +66,16 @@ pub struct Match {
     pub pane_id: PaneId,
     pub text: Text,
     pub selected_for_extraction: bool,
+    pub source_tab_index: usize,
 }
 
 impl Match {
-    pub fn new(pane_id: PaneId, text: Text) -> Self {
+    pub fn new(pane_id: PaneId, text: Text, source_tab_index: usize) -> Self {
         Match {
             pane_id,
             text,
-            selected_for_extraction: false
+            selected_for_extraction: false,
+            source_tab_index,
         }
     }
     pub fn toggle_mark_for_extraction(&mut self) {
@@ -50,6 +101,15 @@ impl Into<PaneId> for &PaneIdHashable {
     }
 }
 
+impl From<PaneId> for PaneIdHashable {
+    fn from(pane_id: PaneId) -> Self {
+        match pane_id {
+            PaneId::Terminal(id) => PaneIdHashable::terminal(id),
+            PaneId::Plugin(id) => PaneIdHashable::plugin(id),
+        }
+    }
+}
+
 impl PaneIdHashable {
     pub fn plugin(pane_id: u32) -> Self {
         PaneIdHashable {
@@ -69,6 +129,13 @@ impl ZellijPlugin for State {
     fn load(&mut self, configuration: BTreeMap<String, String>) {
         request_permission(&[PermissionType::ReadApplicationState, PermissionType::ChangeApplicationState]);
         subscribe(&[EventType::ModeUpdate, EventType::TabUpdate, EventType::PaneUpdate, EventType::Key]);
+        if let Some(default_search_mode) = configuration
+            .get("default_search_mode")
+            .and_then(|value| SearchMode::from_config(value))
+        {
+            self.default_search_mode = default_search_mode;
+        }
+        self.search_mode = self.default_search_mode;
     }
     fn update(&mut self, event: Event) -> bool {
         // TODO:
@@ -98,10 +165,11 @@ impl ZellijPlugin for State {
                             // we don't want to log "UI" panes
                             continue;
                         }
+                        let pane_entry = PaneEntry { title: pane_info.title, tab_index };
                         if pane_info.is_plugin {
-                            self.panes.insert(PaneIdHashable::plugin(pane_info.id), pane_info.title);
+                            self.panes.insert(PaneIdHashable::plugin(pane_info.id), pane_entry);
                         } else {
-                            self.panes.insert(PaneIdHashable::terminal(pane_info.id), pane_info.title);
+                            self.panes.insert(PaneIdHashable::terminal(pane_info.id), pane_entry);
                         }
                     }
                 }
@@ -109,11 +177,17 @@ impl ZellijPlugin for State {
             Event::Key(key) => {
                 match key.bare_key {
                     BareKey::Char(character) if key.has_no_modifiers() => {
+                        self.recent_filter_cursor = None;
                         self.filter.push(character);
                         self.trigger_search();
                         should_render = true;
                     },
+                    BareKey::Char('r') if key.has_modifiers(&[KeyModifier::Ctrl]) => {
+                        self.cycle_recent_filter();
+                        should_render = true;
+                    }
                     BareKey::Backspace if key.has_no_modifiers() => {
+                        self.recent_filter_cursor = None;
                         self.filter.pop();
                         if !self.filter.is_empty() {
                             self.trigger_search();
@@ -124,26 +198,30 @@ impl ZellijPlugin for State {
                     }
                     BareKey::Enter if key.has_no_modifiers() => {
                         if !self.current_matches.is_empty() {
+                            self.remember_filter();
+                            let panes_to_extract = self.panes_to_extract();
                             let should_focus = false;
                             match self.selected_tab_index {
                                 None => {
                                     let new_tab_name = &self.filter;
                                     break_panes_to_new_tab(
-                                        &self.panes_to_extract(),
+                                        &panes_to_extract,
                                         Some(new_tab_name.to_owned()),
                                         should_focus,
                                     );
-                                    self.clear_search();
                                 },
                                 Some(tab_index) => {
                                     break_panes_to_tab_with_index(
-                                        &self.panes_to_extract(),
+                                        &panes_to_extract,
                                         tab_index,
                                         should_focus,
                                     );
-                                    self.clear_search();
                                 }
                             }
+                            for pane_id in panes_to_extract {
+                                self.marked_for_extraction.remove(&pane_id.into());
+                            }
+                            self.clear_search();
                         }
                         should_render = true;
                     }
@@ -157,6 +235,10 @@ impl ZellijPlugin for State {
                         }
                         should_render = true;
                     }
+                    BareKey::Char('t') if key.has_modifiers(&[KeyModifier::Ctrl]) => {
+                        self.cycle_tab_filter();
+                        should_render = true;
+                    }
                     BareKey::Down if key.has_no_modifiers() => {
                         if self.selected_match_index.is_none() && !self.current_matches.is_empty() {
                             self.selected_match_index = Some(0);
@@ -177,6 +259,24 @@ impl ZellijPlugin for State {
                         }
                         should_render = true;
                     }
+                    BareKey::Down if key.has_modifiers(&[KeyModifier::Ctrl]) => {
+                        if self.selected_match_index.is_none() && !self.current_matches.is_empty() {
+                            self.selected_match_index = Some(0);
+                        } else if self.selected_match_index != Some(self.current_matches.len().saturating_sub(1)) {
+                            self.selected_match_index = self.selected_match_index.as_mut().map(|i| *i + 1);
+                        }
+                        self.jump_to_selected_match();
+                        should_render = true;
+                    }
+                    BareKey::Up if key.has_modifiers(&[KeyModifier::Ctrl]) => {
+                        if self.selected_match_index.is_none() && !self.current_matches.is_empty() {
+                            self.selected_match_index = Some(self.current_matches.len().saturating_sub(1));
+                        } else if self.selected_match_index != Some(0) {
+                            self.selected_match_index = self.selected_match_index.as_mut().map(|i| i.saturating_sub(1));
+                        }
+                        self.jump_to_selected_match();
+                        should_render = true;
+                    }
                     BareKey::Right | BareKey::Left if key.has_no_modifiers() => {
                         self.toggle_mark_selected_for_extraction();
                         should_render = true;
@@ -205,12 +305,20 @@ impl ZellijPlugin for State {
         let max_width = Some(cols);
         // SEARCH LINE
         let prompt = "SEARCH PANES:";
-        let search_line = Text::new(format!("{} {}_", prompt, self.filter));
+        let mode_indicator = self.search_mode.indicator();
+        let search_line_text = format!("{} {}_ {}", prompt, self.filter, mode_indicator);
+        let mode_indicator_start = format!("{} {}_ ", prompt, self.filter).chars().count();
+        let mode_indicator_end = mode_indicator_start + mode_indicator.chars().count();
+        let search_line = if self.search_error {
+            Text::new(search_line_text).color_range(1, mode_indicator_start..mode_indicator_end)
+        } else {
+            Text::new(search_line_text).color_range(3, mode_indicator_start..mode_indicator_end)
+        };
         print_text_with_coordinates(search_line, 0, 0, max_width, Some(rows_for_search_line));
 
         // RESULTS TABLE
         let mut current_matches_table = Table::new()
-            .add_row(vec![" ", " ", " "]);
+            .add_row(vec![" ", " ", " ", " "]);
         let panes_selected_for_extraction = self.current_matches.iter().filter(|m| if m.selected_for_extraction { true } else { false }).count();
         let mut first_row_index = self.selected_match_index.map(|s_i| s_i.saturating_sub(rows_for_table / 2)).unwrap_or(0);
         let last_row_index = (first_row_index + rows_for_table).saturating_sub(2); // 1 for the
@@ -232,6 +340,12 @@ impl ZellijPlugin for State {
             } else {
                 row.push(Text::new(format!(" ")));
             }
+            let source_tab_name = self
+                .tabs
+                .get(current_match.source_tab_index)
+                .map(|name| name.as_str())
+                .unwrap_or("?");
+            row.push(Text::new(format!("[from: {}]", source_tab_name)).color_range(3, ..));
             if current_match.selected_for_extraction {
                 for item in row.iter_mut() {
                     *item = item.clone().color_range(0, ..);
@@ -252,20 +366,7 @@ impl ZellijPlugin for State {
         print_text_with_coordinates(Text::new(move_to_text), 0, tab_line_y, None, None);
         let tab_toggle_indication = "<TAB>";
         print_text_with_coordinates(Text::new(tab_toggle_indication).color_range(3, ..), 9, tab_line_y, None, None);
-        let mut tab_x = 9 + 6;
-        for (i, tab) in self.tabs.iter().enumerate() {
-            if self.selected_tab_index == Some(i) {
-                print_ribbon_with_coordinates(Text::new(tab).selected(), tab_x, tab_line_y, None, None);
-            } else {
-                print_ribbon_with_coordinates(Text::new(tab), tab_x, tab_line_y, None, None);
-            }
-            tab_x += tab.chars().count() + 4;
-        }
-        if self.selected_tab_index.is_none() {
-            print_ribbon_with_coordinates(Text::new("[NEW TAB]").selected(), tab_x, tab_line_y, None, None);
-        } else {
-            print_ribbon_with_coordinates(Text::new("[NEW TAB]"), tab_x, tab_line_y, None, None);
-        }
+        self.render_move_to_tab_ribbons(cols, tab_line_y);
 
 
         // CONTROLS LINE
@@ -279,18 +380,24 @@ impl ZellijPlugin for State {
         let arrows_legend = "Navigate and select entries";
         let arrow_legend_start_pos = enter_text.len() + enter_legend.len() + 5; // 5 is the spaces
         let arrow_legend_end_pos = arrow_legend_start_pos + arrows_text.chars().count();
+        let preview_text = "<CTRL+↓↑>";
+        let preview_legend = "Jump to pane (no auto-return)";
+        let preview_text_start_pos = arrow_legend_end_pos + arrows_legend.len() + 5; // 5 is the spaces
+        let preview_text_end_pos = preview_text_start_pos + preview_text.chars().count();
         let controls_line_y = rows;
         let text = if panes_selected_for_extraction > 0 {
             let pane_count_start_pos = enter_text.chars().count() + 8;
             let pane_count_end_pos = pane_count_start_pos + format!("{}", panes_selected_for_extraction).chars().count();
-            Text::new(format!("{} - {}, {} - {}", enter_text, enter_legend, arrows_text, arrows_legend))
+            Text::new(format!("{} - {}, {} - {}, {} - {}", enter_text, enter_legend, arrows_text, arrows_legend, preview_text, preview_legend))
                 .color_range(3, ..enter_text.len())
                 .color_range(0, pane_count_start_pos..pane_count_end_pos)
                 .color_range(3, arrow_legend_start_pos..arrow_legend_end_pos)
+                .color_range(3, preview_text_start_pos..preview_text_end_pos)
         } else {
-            Text::new(format!("{} - {}, {} - {}", enter_text, enter_legend, arrows_text, arrows_legend))
+            Text::new(format!("{} - {}, {} - {}, {} - {}", enter_text, enter_legend, arrows_text, arrows_legend, preview_text, preview_legend))
                 .color_range(3, ..enter_text.len())
                 .color_range(3, arrow_legend_start_pos..arrow_legend_end_pos)
+                .color_range(3, preview_text_start_pos..preview_text_end_pos)
         };
         print_text_with_coordinates(
             text,
@@ -309,31 +416,264 @@ impl ZellijPlugin for State {
 }
 
 impl State {
+    // Falls back to a scrollable window around the selected tab when the ribbons don't all fit.
+    fn render_move_to_tab_ribbons(&self, cols: usize, tab_line_y: usize) {
+        let ribbons_start_x = 9 + 6; // "<TAB>" legend starts at 9, is 6 chars wide including gap
+        let ribbon_width = |s: &str| s.chars().count() + 4;
+        let new_tab_width = ribbon_width("[NEW TAB]");
+        let total_tabs_width = self.tabs.iter().map(|t| ribbon_width(t)).sum::<usize>();
+        let fits = ribbons_start_x + total_tabs_width + new_tab_width <= cols;
+        let mut tab_x = ribbons_start_x;
+        if self.tabs.is_empty() || fits {
+            for (i, tab) in self.tabs.iter().enumerate() {
+                self.print_move_to_tab_ribbon(tab, i, tab_x, tab_line_y);
+                tab_x += ribbon_width(tab);
+            }
+        } else {
+            let available_width = cols.saturating_sub(ribbons_start_x).saturating_sub(new_tab_width);
+            let marker_width = |hidden: usize| if hidden == 0 { 0 } else { ribbon_width(&format!("‹ +{}", hidden)) };
+            let selected = self.selected_tab_index.unwrap_or(0);
+            let mut window_start = selected;
+            let mut window_end = selected;
+            let max_label_chars = available_width.saturating_sub(4);
+            let selected_label = if self.tabs[selected].chars().count() > max_label_chars {
+                self.tabs[selected]
+                    .chars()
+                    .take(max_label_chars.saturating_sub(1))
+                    .chain(std::iter::once('…'))
+                    .collect::<String>()
+            } else {
+                self.tabs[selected].clone()
+            };
+            let mut content_width = ribbon_width(&selected_label);
+            loop {
+                let can_grow_right = window_end + 1 < self.tabs.len();
+                let can_grow_left = window_start > 0;
+                if !can_grow_right && !can_grow_left {
+                    break;
+                }
+                let hidden_on_right = self.tabs.len() - 1 - window_end;
+                let grow_right = can_grow_right && (!can_grow_left || hidden_on_right <= window_start);
+                let (next_start, next_end) = if grow_right {
+                    (window_start, window_end + 1)
+                } else {
+                    (window_start - 1, window_end)
+                };
+                let grown_tab = if grow_right { &self.tabs[next_end] } else { &self.tabs[next_start] };
+                let next_content_width = content_width + ribbon_width(grown_tab);
+                let next_total = next_content_width
+                    + marker_width(next_start)
+                    + marker_width(self.tabs.len() - 1 - next_end);
+                if next_total > available_width {
+                    break;
+                }
+                window_start = next_start;
+                window_end = next_end;
+                content_width = next_content_width;
+            }
+            if window_start > 0 {
+                let hidden_before = window_start;
+                let marker = format!("‹ +{}", hidden_before);
+                print_ribbon_with_coordinates(Text::new(&marker).color_range(2, ..), tab_x, tab_line_y, None, None);
+                tab_x += ribbon_width(&marker);
+            }
+            for i in window_start..=window_end {
+                let label = if i == selected { selected_label.as_str() } else { self.tabs[i].as_str() };
+                self.print_move_to_tab_ribbon(label, i, tab_x, tab_line_y);
+                tab_x += ribbon_width(label);
+            }
+            if window_end + 1 < self.tabs.len() {
+                let hidden_after = self.tabs.len() - 1 - window_end;
+                let marker = format!("+{} ›", hidden_after);
+                print_ribbon_with_coordinates(Text::new(&marker).color_range(2, ..), tab_x, tab_line_y, None, None);
+                tab_x += ribbon_width(&marker);
+            }
+        }
+        if self.selected_tab_index.is_none() {
+            print_ribbon_with_coordinates(Text::new("[NEW TAB]").selected(), tab_x, tab_line_y, None, None);
+        } else {
+            print_ribbon_with_coordinates(Text::new("[NEW TAB]"), tab_x, tab_line_y, None, None);
+        }
+    }
+    fn print_move_to_tab_ribbon(&self, tab: &str, index: usize, tab_x: usize, tab_line_y: usize) {
+        if self.selected_tab_index == Some(index) {
+            print_ribbon_with_coordinates(Text::new(tab).selected(), tab_x, tab_line_y, None, None);
+        } else {
+            print_ribbon_with_coordinates(Text::new(tab), tab_x, tab_line_y, None, None);
+        }
+    }
     pub fn trigger_search(&mut self) {
         self.current_matches.clear();
-        let filter_len = self.filter.chars().count();
-        let lc_filter = self.filter.to_lowercase();
-        for (pane_id, pane_title) in &self.panes {
-            let lc_pane_title = pane_title.to_lowercase();
-            let matches = lc_pane_title.match_indices(&lc_filter).collect::<Vec<_>>();
+        self.search_error = false;
+        let (mode, pattern) = self.parse_filter();
+        self.search_mode = mode;
+        match mode {
+            SearchMode::Regex => self.trigger_regex_search(&pattern),
+            SearchMode::Exact => self.trigger_exact_search(&pattern),
+            SearchMode::Fuzzy => self.trigger_fuzzy_search(&pattern),
+        }
+    }
+    fn parse_filter(&self) -> (SearchMode, String) {
+        if let Some(stripped) = self.filter.strip_prefix('/') {
+            (SearchMode::Regex, stripped.to_owned())
+        } else if let Some(stripped) = self.filter.strip_prefix('\'') {
+            (SearchMode::Exact, stripped.to_owned())
+        } else {
+            (self.default_search_mode, self.filter.clone())
+        }
+    }
+    fn trigger_regex_search(&mut self, pattern: &str) {
+        let regex = match Regex::new(&format!("(?i){}", pattern)) {
+            Ok(regex) => regex,
+            Err(_) => {
+                self.search_error = true;
+                self.finalize_matches(vec![]);
+                return;
+            }
+        };
+        let mut raw_matches = vec![];
+        for (pane_id, pane_entry) in &self.panes {
+            let matches = regex.find_iter(&pane_entry.title).collect::<Vec<_>>();
+            if !matches.is_empty() {
+                let mut text = Text::new(&pane_entry.title);
+                for found in matches {
+                    let start = pane_entry.title[..found.start()].chars().count();
+                    let end = pane_entry.title[..found.end()].chars().count();
+                    text = text.color_range(3, start..end);
+                }
+                raw_matches.push((pane_entry.tab_index, Match::new(pane_id.into(), text, pane_entry.tab_index)));
+            }
+        }
+        self.finalize_matches(raw_matches);
+    }
+    fn trigger_exact_search(&mut self, pattern: &str) {
+        let filter_len = pattern.chars().count();
+        let mut raw_matches = vec![];
+        for (pane_id, pane_entry) in &self.panes {
+            let matches = pane_entry.title.match_indices(pattern).collect::<Vec<_>>();
             if !matches.is_empty() {
-                let mut text = Text::new(pane_title);
-                for (match_index, _) in matches {
-                    text = text.color_range(3, match_index..match_index + filter_len);
+                let mut text = Text::new(&pane_entry.title);
+                for (byte_index, _) in matches {
+                    let start = pane_entry.title[..byte_index].chars().count();
+                    text = text.color_range(3, start..start + filter_len);
+                }
+                raw_matches.push((pane_entry.tab_index, Match::new(pane_id.into(), text, pane_entry.tab_index)));
+            }
+        }
+        self.finalize_matches(raw_matches);
+    }
+    fn trigger_fuzzy_search(&mut self, pattern: &str) {
+        let lc_filter = pattern.to_lowercase().chars().collect::<Vec<_>>();
+        if lc_filter.is_empty() {
+            self.finalize_matches(vec![]);
+            return;
+        }
+        let mut scored_matches = vec![];
+        for (pane_id, pane_entry) in &self.panes {
+            if let Some((score, matched_char_indices)) = fuzzy_match(&lc_filter, &pane_entry.title) {
+                let mut text = Text::new(&pane_entry.title);
+                for char_index in matched_char_indices {
+                    text = text.color_range(3, char_index..char_index + 1);
                 }
-                self.current_matches.push(Match::new(pane_id.into(), text));
+                scored_matches.push((score, pane_entry.tab_index, Match::new(pane_id.into(), text, pane_entry.tab_index)));
             }
         }
+        scored_matches.sort_by(|(score_a, ..), (score_b, ..)| score_b.cmp(score_a));
+        let raw_matches = scored_matches.into_iter().map(|(_, tab_index, m)| (tab_index, m)).collect();
+        self.finalize_matches(raw_matches);
+    }
+    // Tracks matched tabs for `tab_filter` cycling, then applies it and groups rows by source tab.
+    fn finalize_matches(&mut self, raw_matches: Vec<(usize, Match)>) {
+        let mut matching_tab_indices = raw_matches.iter().map(|(tab_index, _)| *tab_index).collect::<Vec<_>>();
+        matching_tab_indices.sort_unstable();
+        matching_tab_indices.dedup();
+        self.matching_tab_indices = matching_tab_indices;
+        let tab_filter = self.tab_filter;
+        let marked_for_extraction = &self.marked_for_extraction;
+        let mut current_matches = raw_matches
+            .into_iter()
+            .filter(|(tab_index, _)| tab_filter.map_or(true, |filter| filter == *tab_index))
+            .map(|(_, mut m)| {
+                m.selected_for_extraction = marked_for_extraction.contains(&m.pane_id.into());
+                m
+            })
+            .collect::<Vec<_>>();
+        // stable sort: groups rows by source tab, preserving relevance order within each
+        current_matches.sort_by_key(|m| m.source_tab_index);
+        self.current_matches = current_matches;
+        self.selected_match_index = None;
+    }
+    fn cycle_tab_filter(&mut self) {
+        if self.matching_tab_indices.is_empty() {
+            return;
+        }
+        self.tab_filter = match self.tab_filter {
+            None => Some(self.matching_tab_indices[0]),
+            Some(current) => {
+                let current_pos = self.matching_tab_indices.iter().position(|t| *t == current);
+                match current_pos {
+                    Some(pos) if pos + 1 < self.matching_tab_indices.len() => Some(self.matching_tab_indices[pos + 1]),
+                    _ => None,
+                }
+            }
+        };
+        self.trigger_search();
     }
     pub fn clear_search(&mut self) {
         self.filter.clear();
         self.current_matches.clear();
         self.selected_match_index = None;
+        self.search_error = false;
+        self.search_mode = self.default_search_mode;
+        self.tab_filter = None;
+        self.matching_tab_indices.clear();
+    }
+    // One-way jump: once focus leaves this plugin it stops receiving Event::Key, so the user
+    // returns via their own zellij pane-focus keybind, not a keystroke here.
+    fn jump_to_selected_match(&self) {
+        if let Some(current_match) = self.selected_match_index.and_then(|index| self.current_matches.get(index)) {
+            // 2-arg signatures: pinned zellij-tile version predates the 0.44.x third bool param.
+            match current_match.pane_id {
+                PaneId::Terminal(id) => focus_terminal_pane(id, false),
+                PaneId::Plugin(id) => focus_pane_with_id(PaneId::Plugin(id), false),
+            }
+        }
     }
     pub fn toggle_mark_selected_for_extraction(&mut self) {
         if let Some(index) = self.selected_match_index {
-            self.current_matches.get_mut(index).map(|m| m.toggle_mark_for_extraction());
+            if let Some(current_match) = self.current_matches.get_mut(index) {
+                current_match.toggle_mark_for_extraction();
+                let pane_id_hashable = current_match.pane_id.into();
+                if current_match.selected_for_extraction {
+                    self.marked_for_extraction.insert(pane_id_hashable);
+                } else {
+                    self.marked_for_extraction.remove(&pane_id_hashable);
+                }
+            }
+        }
+    }
+    // Pushes the committed filter onto the recent-filters ring buffer (most recent first, deduped).
+    fn remember_filter(&mut self) {
+        if self.filter.is_empty() {
+            return;
         }
+        self.recent_filters.retain(|filter| filter != &self.filter);
+        self.recent_filters.push_front(self.filter.clone());
+        self.recent_filters.truncate(RECENT_FILTERS_CAPACITY);
+        self.recent_filter_cursor = None;
+    }
+    fn cycle_recent_filter(&mut self) {
+        if self.recent_filters.is_empty() {
+            return;
+        }
+        let next_index = match self.recent_filter_cursor {
+            None => 0,
+            Some(index) if index + 1 < self.recent_filters.len() => index + 1,
+            Some(index) => index,
+        };
+        self.recent_filter_cursor = Some(next_index);
+        self.filter = self.recent_filters[next_index].clone();
+        self.trigger_search();
     }
     pub fn panes_to_extract(&self) -> Vec<PaneId> {
         let pane_ids_selected_for_extraction = self.current_matches.iter().filter_map(|m| if m.selected_for_extraction { Some(m.pane_id) } else { None }).collect::<Vec<_>>();
@@ -345,3 +685,47 @@ impl State {
         }
     }
 }
+
+// fzf-style subsequence scorer; returns the score and matched char indices, or None if no match.
+fn fuzzy_match(filter: &[char], title: &str) -> Option<(i64, Vec<usize>)> {
+    let title_chars = title.char_indices().collect::<Vec<_>>();
+    let mut filter_pos = 0;
+    let mut matched_char_indices = vec![];
+    let mut last_matched_char_pos: Option<usize> = None;
+    let mut score: i64 = 0;
+    for (char_pos, (_, title_char)) in title_chars.iter().enumerate() {
+        if filter_pos >= filter.len() {
+            break;
+        }
+        let lc_title_char = title_char.to_lowercase().next().unwrap_or(*title_char);
+        if lc_title_char != filter[filter_pos] {
+            continue;
+        }
+        score += 1;
+        let is_word_start = char_pos == 0
+            || title_chars
+                .get(char_pos - 1)
+                .map(|(_, c)| is_word_separator(*c))
+                .unwrap_or(false);
+        if is_word_start {
+            score += 2;
+        }
+        match last_matched_char_pos {
+            Some(last_char_pos) if char_pos == last_char_pos + 1 => score += 3,
+            Some(last_char_pos) => score -= (char_pos - last_char_pos - 1) as i64,
+            None => {}
+        }
+        matched_char_indices.push(char_pos);
+        last_matched_char_pos = Some(char_pos);
+        filter_pos += 1;
+    }
+    if filter_pos == filter.len() {
+        Some((score, matched_char_indices))
+    } else {
+        None
+    }
+}
+
+fn is_word_separator(c: char) -> bool {
+    matches!(c, ' ' | '-' | '_' | '.' | '/')
+}